@@ -1,10 +1,10 @@
 use super::StrChunkMut;
-use bytes::{Bytes, BytesMut, IntoBuf};
+use bytes::{BufMut, Bytes, BytesMut, IntoBuf};
 use std::{
     borrow::Borrow,
     error::Error,
     fmt::{self, Debug, Display},
-    io::Cursor,
+    io::{self, Cursor, Read},
     iter::FromIterator,
     ops::Deref,
     str,
@@ -23,6 +23,29 @@ impl StrChunk {
         }
     }
 
+    /// Validates `bytes` as UTF-8 and wraps it without copying.
+    ///
+    /// On failure, the `Bytes` is handed back unchanged via
+    /// [`FromUtf8Error::into_bytes`] alongside the `std::str::Utf8Error`
+    /// that `str::from_utf8` produced.
+    pub fn from_utf8(bytes: Bytes) -> Result<StrChunk, FromUtf8Error> {
+        match str::from_utf8(&bytes) {
+            Ok(_) => Ok(StrChunk { bytes }),
+            Err(error) => Err(FromUtf8Error { bytes, error }),
+        }
+    }
+
+    /// Wraps `bytes` without validating that it is UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8; violating this is undefined behavior
+    /// since `StrChunk` derefs to `str`.
+    #[inline]
+    pub unsafe fn from_utf8_unchecked(bytes: Bytes) -> StrChunk {
+        StrChunk { bytes }
+    }
+
     pub fn extract_utf8(
         src: &mut BytesMut,
     ) -> Result<Option<StrChunk>, ExtractUtf8Error> {
@@ -57,12 +80,171 @@ impl StrChunk {
         }
     }
 
+    /// Lossily decodes `src` into a single `StrChunk`, substituting
+    /// `U+FFFD REPLACEMENT CHARACTER` for each invalid run.
+    ///
+    /// Valid runs are still shared with `src` via `Bytes::slice`, but since
+    /// the result has to be a single contiguous `StrChunk`, any replacement
+    /// forces a copy of the surrounding text into a fresh buffer.
+    pub fn from_utf8_lossy(src: Bytes) -> StrChunk {
+        let mut chunks = StrChunk::utf8_chunks(src);
+        let first = match chunks.next() {
+            None => return StrChunk::default(),
+            // No invalid run: the whole input is valid, return it uncopied.
+            Some(chunk) => {
+                if chunk.invalid.is_empty() {
+                    return chunk.valid;
+                }
+                chunk
+            }
+        };
+        let mut out = StrChunkMut::new();
+        for chunk in Some(first).into_iter().chain(chunks) {
+            out.push_str(&chunk.valid);
+            if !chunk.invalid.is_empty() {
+                out.push('\u{FFFD}');
+            }
+        }
+        out.into()
+    }
+
+    /// Returns an iterator over the valid and invalid UTF-8 runs in `src`,
+    /// modeled on the standard library's `<[u8]>::utf8_chunks`.
+    pub fn utf8_chunks(src: Bytes) -> Utf8Chunks {
+        Utf8Chunks { rest: src }
+    }
+
+    /// Splits the chunk into two at the given byte index, sharing the
+    /// underlying `Bytes` with no copy.
+    ///
+    /// Afterwards `self` contains `[at, len)` and the returned `StrChunk`
+    /// contains `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is not a char boundary, same as `str` slicing.
+    pub fn split_to(&mut self, at: usize) -> StrChunk {
+        assert!(self.is_char_boundary(at), "byte index {} is not a char boundary", at);
+        StrChunk {
+            bytes: self.bytes.split_to(at),
+        }
+    }
+
+    /// Splits the chunk into two at the given byte index, sharing the
+    /// underlying `Bytes` with no copy.
+    ///
+    /// Afterwards `self` contains `[0, at)` and the returned `StrChunk`
+    /// contains `[at, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is not a char boundary, same as `str` slicing.
+    pub fn split_off(&mut self, at: usize) -> StrChunk {
+        assert!(self.is_char_boundary(at), "byte index {} is not a char boundary", at);
+        StrChunk {
+            bytes: self.bytes.split_off(at),
+        }
+    }
+
+    /// Returns the `[begin, end)` byte range as its own `StrChunk`, sharing
+    /// the underlying `Bytes` with no copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `begin` or `end` is not a char boundary, same as `str`
+    /// slicing.
+    pub fn slice(&self, begin: usize, end: usize) -> StrChunk {
+        assert!(
+            self.is_char_boundary(begin),
+            "byte index {} is not a char boundary",
+            begin
+        );
+        assert!(self.is_char_boundary(end), "byte index {} is not a char boundary", end);
+        StrChunk {
+            bytes: self.bytes.slice(begin, end),
+        }
+    }
+
+    /// Splits the chunk on each match of `pat`, yielding zero-copy
+    /// `StrChunk` pieces, analogous to `str::split`.
+    pub fn split<P: Pattern>(&self, pat: P) -> Split<P> {
+        Split {
+            rest: Some(self.clone()),
+            pat,
+            terminator: false,
+        }
+    }
+
+    /// Like [`StrChunk::split`], but a trailing empty piece after a final
+    /// match of `pat` is skipped, analogous to `str::split_terminator`.
+    pub fn split_terminator<P: Pattern>(&self, pat: P) -> Split<P> {
+        Split {
+            rest: Some(self.clone()),
+            pat,
+            terminator: true,
+        }
+    }
+
     #[inline]
     fn as_str(&self) -> &str {
         unsafe { str::from_utf8_unchecked(&self.bytes) }
     }
 }
 
+/// A valid UTF-8 run together with the (possibly empty) invalid run that
+/// follows it, as yielded by [`Utf8Chunks`].
+///
+/// A non-empty `invalid` with no `error_len` information available (i.e. a
+/// truncated sequence at the end of the input) is only produced for the
+/// final item, letting callers tell "needs more input" apart from
+/// "corrupt".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf8Chunk {
+    pub valid: StrChunk,
+    pub invalid: Bytes,
+}
+
+/// Iterator over the [`Utf8Chunk`]s of a byte buffer, created by
+/// [`StrChunk::utf8_chunks`].
+#[derive(Clone, Debug)]
+pub struct Utf8Chunks {
+    rest: Bytes,
+}
+
+impl Iterator for Utf8Chunks {
+    type Item = Utf8Chunk;
+
+    fn next(&mut self) -> Option<Utf8Chunk> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        match str::from_utf8(&self.rest) {
+            Ok(_) => {
+                let valid = StrChunk {
+                    bytes: self.rest.split_to(self.rest.len()),
+                };
+                Some(Utf8Chunk {
+                    valid,
+                    invalid: Bytes::new(),
+                })
+            }
+            Err(e) => {
+                let valid_up_to = e.valid_up_to();
+                let error_len = e
+                    .error_len()
+                    .unwrap_or_else(|| self.rest.len() - valid_up_to);
+                let valid = StrChunk {
+                    bytes: self.rest.slice(0, valid_up_to),
+                };
+                let invalid = self.rest.slice(valid_up_to, valid_up_to + error_len);
+                self.rest = self.rest.slice_from(valid_up_to + error_len);
+                Some(Utf8Chunk { valid, invalid })
+            }
+        }
+    }
+}
+
 impl Debug for StrChunk {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         Debug::fmt(self.as_str(), f)
@@ -158,6 +340,13 @@ impl FromIterator<char> for StrChunk {
     }
 }
 
+/// Error returned by [`StrChunk::extract_utf8`] and, in strict mode, by
+/// [`Utf8Decoder::feed`] when invalid UTF-8 is found.
+///
+/// `extract_utf8` leaves the undrained tail (the bad run and everything
+/// after it) in the caller's `BytesMut` for inspection or recovery.
+/// `Utf8Decoder::feed` has no such buffer to leave it in: anything past the
+/// bad run is simply dropped along with the error.
 #[derive(Clone, Debug)]
 pub struct ExtractUtf8Error {
     extracted: Option<StrChunk>,
@@ -181,3 +370,501 @@ impl Display for ExtractUtf8Error {
 }
 
 impl Error for ExtractUtf8Error {}
+
+/// Error returned by [`StrChunk::from_utf8`] when `bytes` is not valid
+/// UTF-8, retaining the original `Bytes` so the caller can recover it.
+#[derive(Clone, Debug)]
+pub struct FromUtf8Error {
+    bytes: Bytes,
+    error: str::Utf8Error,
+}
+
+impl FromUtf8Error {
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    pub fn utf8_error(&self) -> str::Utf8Error {
+        self.error
+    }
+}
+
+impl Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl Error for FromUtf8Error {}
+
+/// Maximum number of trailing bytes an incomplete UTF-8 sequence can leave
+/// behind between `feed` calls (the longest encoding is 4 bytes).
+const MAX_PENDING_LEN: usize = 3;
+
+#[derive(Clone, Debug)]
+struct Pending {
+    buf: [u8; MAX_PENDING_LEN],
+    len: usize,
+}
+
+impl Pending {
+    fn new() -> Pending {
+        Pending {
+            buf: [0; MAX_PENDING_LEN],
+            len: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn set(&mut self, bytes: &[u8]) {
+        debug_assert!(bytes.len() <= MAX_PENDING_LEN);
+        self.len = bytes.len();
+        self.buf[..bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+/// Stateful streaming UTF-8 decoder that carries an incomplete trailing
+/// sequence across `feed` calls, so a multi-byte character split across two
+/// reads decodes correctly without the caller stitching buffers together.
+///
+/// In strict mode (`Utf8Decoder::new`), genuinely invalid bytes are
+/// reported as an `ExtractUtf8Error`, same as `StrChunk::extract_utf8`. In
+/// lossy mode (`Utf8Decoder::lossy`), they are replaced with U+FFFD and
+/// decoding continues.
+#[derive(Clone, Debug)]
+pub struct Utf8Decoder {
+    lossy: bool,
+    pending: Pending,
+}
+
+impl Default for Utf8Decoder {
+    fn default() -> Utf8Decoder {
+        Utf8Decoder::new()
+    }
+}
+
+impl Utf8Decoder {
+    pub fn new() -> Utf8Decoder {
+        Utf8Decoder {
+            lossy: false,
+            pending: Pending::new(),
+        }
+    }
+
+    pub fn lossy() -> Utf8Decoder {
+        Utf8Decoder {
+            lossy: true,
+            pending: Pending::new(),
+        }
+    }
+
+    /// Decodes as much of `input` as possible, carrying any incomplete
+    /// trailing sequence over to the next call instead of erroring.
+    ///
+    /// In strict mode, invalid bytes found before the end of `input` are
+    /// reported as an `ExtractUtf8Error`, but unlike `StrChunk::extract_utf8`
+    /// (whose caller keeps the undrained tail in its own `BytesMut`), any
+    /// bytes after the bad run are dropped along with the error; they are
+    /// not stashed in the decoder or recoverable from the error. Callers
+    /// that need to resync after a strict-mode error should not trust
+    /// anything past `error_len` of the offending run to still be around.
+    pub fn feed(&mut self, input: Bytes) -> Result<StrChunk, ExtractUtf8Error> {
+        let mut rest = if self.pending.is_empty() {
+            input
+        } else {
+            let mut combined = BytesMut::with_capacity(self.pending.len + input.len());
+            combined.extend_from_slice(self.pending.as_slice());
+            combined.extend_from_slice(&input);
+            combined.freeze()
+        };
+        self.pending.clear();
+
+        let mut out: Option<StrChunkMut> = None;
+        loop {
+            match str::from_utf8(&rest) {
+                Ok(_) => {
+                    let valid = StrChunk { bytes: rest };
+                    return Ok(match out {
+                        None => valid,
+                        Some(mut buf) => {
+                            buf.push_str(&valid);
+                            buf.into()
+                        }
+                    });
+                }
+                Err(e) => {
+                    let valid_up_to = e.valid_up_to();
+                    let valid = StrChunk {
+                        bytes: rest.slice(0, valid_up_to),
+                    };
+                    match e.error_len() {
+                        None => {
+                            // Incomplete sequence at the end: carry it over.
+                            self.pending.set(rest.slice_from(valid_up_to).as_ref());
+                            return Ok(match out {
+                                None => valid,
+                                Some(mut buf) => {
+                                    buf.push_str(&valid);
+                                    buf.into()
+                                }
+                            });
+                        }
+                        Some(error_len) if self.lossy => {
+                            let mut buf = out.take().unwrap_or_else(StrChunkMut::new);
+                            buf.push_str(&valid);
+                            buf.push('\u{FFFD}');
+                            out = Some(buf);
+                            rest = rest.slice_from(valid_up_to + error_len);
+                        }
+                        Some(error_len) => {
+                            let extracted = match out {
+                                Some(mut buf) => {
+                                    buf.push_str(&valid);
+                                    Some(buf.into())
+                                }
+                                None if valid_up_to == 0 => None,
+                                None => Some(valid),
+                            };
+                            return Err(ExtractUtf8Error {
+                                extracted,
+                                error_len,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Finalizes the decoder, handling any trailing bytes left over from
+    /// the last `feed` call.
+    ///
+    /// In lossy mode a dangling incomplete sequence becomes a single
+    /// U+FFFD; in strict mode it is reported as an error.
+    pub fn finish(self) -> Result<StrChunk, ExtractUtf8Error> {
+        if self.pending.is_empty() {
+            return Ok(StrChunk::default());
+        }
+        if self.lossy {
+            Ok(StrChunk::from("\u{FFFD}"))
+        } else {
+            Err(ExtractUtf8Error {
+                extracted: None,
+                error_len: self.pending.len,
+            })
+        }
+    }
+}
+
+/// Adapter that reads from an `io::Read` and yields decoded `StrChunk`s,
+/// carrying an incomplete multi-byte sequence across fills via an internal
+/// [`Utf8Decoder`] so a character straddling two reads is never split.
+#[derive(Debug)]
+pub struct StrChunkReader<R> {
+    inner: R,
+    decoder: Option<Utf8Decoder>,
+    buf: BytesMut,
+    capacity: usize,
+}
+
+impl<R: Read> StrChunkReader<R> {
+    const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+    /// Creates a strict reader with a default fill buffer capacity.
+    pub fn new(inner: R) -> StrChunkReader<R> {
+        StrChunkReader::with_capacity(Self::DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a strict reader with a fill buffer of the given capacity.
+    pub fn with_capacity(capacity: usize, inner: R) -> StrChunkReader<R> {
+        StrChunkReader {
+            inner,
+            decoder: Some(Utf8Decoder::new()),
+            buf: BytesMut::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Creates a reader that replaces invalid bytes with U+FFFD instead of
+    /// erroring, with a default fill buffer capacity.
+    pub fn lossy(inner: R) -> StrChunkReader<R> {
+        StrChunkReader::lossy_with_capacity(Self::DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a lossy reader with a fill buffer of the given capacity.
+    pub fn lossy_with_capacity(capacity: usize, inner: R) -> StrChunkReader<R> {
+        StrChunkReader {
+            inner,
+            decoder: Some(Utf8Decoder::lossy()),
+            buf: BytesMut::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+impl<R: Read> Iterator for StrChunkReader<R> {
+    type Item = io::Result<StrChunk>;
+
+    fn next(&mut self) -> Option<io::Result<StrChunk>> {
+        loop {
+            self.decoder.as_ref()?;
+
+            if self.buf.remaining_mut() < self.capacity {
+                self.buf.reserve(self.capacity);
+            }
+
+            // Safe because `read` only ever initializes the bytes it
+            // reports reading, and we only advance by that many.
+            let n = unsafe {
+                let n = match self.inner.read(self.buf.bytes_mut()) {
+                    Ok(n) => n,
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        self.decoder = None;
+                        return Some(Err(e));
+                    }
+                };
+                self.buf.advance_mut(n);
+                n
+            };
+
+            if n == 0 {
+                let decoder = self.decoder.take().unwrap();
+                return match decoder.finish() {
+                    Ok(ref chunk) if chunk.is_empty() => None,
+                    Ok(chunk) => Some(Ok(chunk)),
+                    Err(e) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+                };
+            }
+
+            let input = self.buf.split_to(n).freeze();
+            match self.decoder.as_mut().unwrap().feed(input) {
+                Ok(ref chunk) if chunk.is_empty() => continue,
+                Ok(chunk) => return Some(Ok(chunk)),
+                Err(e) => {
+                    self.decoder = None;
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e)));
+                }
+            }
+        }
+    }
+}
+
+/// A pattern [`StrChunk::split`] and [`StrChunk::split_terminator`] can
+/// search for, in the spirit of the standard library's (still unstable)
+/// `core::str::pattern::Pattern`.
+///
+/// Implemented for `char` and `&str`.
+///
+/// An empty `&str` pattern never matches (same as treating it as "always
+/// absent" rather than `str::find`'s "matches at every position"), since
+/// matching a zero-length pattern would make `Split` loop forever without
+/// ever advancing past the match.
+pub trait Pattern {
+    #[doc(hidden)]
+    fn find_in(&self, s: &str) -> Option<(usize, usize)>;
+}
+
+impl Pattern for char {
+    fn find_in(&self, s: &str) -> Option<(usize, usize)> {
+        s.find(*self).map(|start| (start, start + self.len_utf8()))
+    }
+}
+
+impl Pattern for &str {
+    fn find_in(&self, s: &str) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        s.find(*self).map(|start| (start, start + self.len()))
+    }
+}
+
+/// Iterator over `StrChunk` pieces, created by [`StrChunk::split`] and
+/// [`StrChunk::split_terminator`].
+#[derive(Debug)]
+pub struct Split<P> {
+    rest: Option<StrChunk>,
+    pat: P,
+    terminator: bool,
+}
+
+impl<P: Pattern> Iterator for Split<P> {
+    type Item = StrChunk;
+
+    fn next(&mut self) -> Option<StrChunk> {
+        let mut rest = self.rest.take()?;
+        match self.pat.find_in(&rest) {
+            Some((start, end)) => {
+                let tail = rest.split_off(end);
+                rest.split_off(start);
+                self.rest = Some(tail);
+                Some(rest)
+            }
+            None => {
+                if self.terminator && rest.is_empty() {
+                    None
+                } else {
+                    Some(rest)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StrChunk, Utf8Decoder};
+    use bytes::Bytes;
+
+    #[test]
+    fn split_empty_str_pattern_never_matches() {
+        let chunk = StrChunk::from("abc");
+        let pieces: Vec<String> = chunk.split("").map(|p| p.to_string()).collect();
+        assert_eq!(pieces, vec!["abc".to_string()]);
+    }
+
+    #[test]
+    fn split_terminator_empty_str_pattern_never_matches() {
+        let chunk = StrChunk::from("abc");
+        let pieces: Vec<String> = chunk.split_terminator("").map(|p| p.to_string()).collect();
+        assert_eq!(pieces, vec!["abc".to_string()]);
+    }
+
+    #[test]
+    fn utf8_chunks_valid_only() {
+        let src = Bytes::from_static(b"hello world");
+        let chunks: Vec<_> = StrChunk::utf8_chunks(src).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(&*chunks[0].valid, "hello world");
+        assert!(chunks[0].invalid.is_empty());
+    }
+
+    #[test]
+    fn utf8_chunks_single_invalid_run() {
+        let src = Bytes::from_static(b"ab\xFFcd");
+        let chunks: Vec<_> = StrChunk::utf8_chunks(src).collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(&*chunks[0].valid, "ab");
+        assert_eq!(chunks[0].invalid.as_ref(), &[0xFF][..]);
+        assert_eq!(&*chunks[1].valid, "cd");
+        assert!(chunks[1].invalid.is_empty());
+    }
+
+    #[test]
+    fn utf8_chunks_trailing_incomplete_is_distinguishable_from_corrupt() {
+        // 0xC3 alone is the lead byte of a 2-byte sequence with nothing
+        // following it: "needs more input", not "corrupt".
+        let src = Bytes::from_static(b"ab\xC3");
+        let chunks: Vec<_> = StrChunk::utf8_chunks(src).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(&*chunks[0].valid, "ab");
+        assert_eq!(chunks[0].invalid.as_ref(), &[0xC3][..]);
+    }
+
+    #[test]
+    fn from_utf8_lossy_substitutes_each_invalid_run() {
+        let src = Bytes::from_static(b"ab\xFFcd\xFF");
+        let lossy = StrChunk::from_utf8_lossy(src);
+        assert_eq!(&*lossy, "ab\u{FFFD}cd\u{FFFD}");
+    }
+
+    #[test]
+    fn from_utf8_lossy_leaves_valid_input_unchanged() {
+        let src = Bytes::from_static(b"hello");
+        let lossy = StrChunk::from_utf8_lossy(src);
+        assert_eq!(&*lossy, "hello");
+    }
+
+    #[test]
+    fn from_utf8_accepts_valid_bytes() {
+        let chunk = StrChunk::from_utf8(Bytes::from_static(b"hello")).unwrap();
+        assert_eq!(&*chunk, "hello");
+    }
+
+    #[test]
+    fn from_utf8_rejects_invalid_bytes_and_returns_them() {
+        let err = StrChunk::from_utf8(Bytes::from_static(b"a\xFFb")).unwrap_err();
+        assert_eq!(err.utf8_error().valid_up_to(), 1);
+        assert_eq!(err.into_bytes().as_ref(), &b"a\xFFb"[..]);
+    }
+
+    #[test]
+    fn from_utf8_unchecked_wraps_without_validating() {
+        let chunk = unsafe { StrChunk::from_utf8_unchecked(Bytes::from_static(b"hello")) };
+        assert_eq!(&*chunk, "hello");
+    }
+
+    #[test]
+    fn decoder_reassembles_two_byte_char_split_across_feeds() {
+        // "é" is 0xC3 0xA9; split right after the lead byte.
+        let mut decoder = Utf8Decoder::new();
+        let out1 = decoder.feed(Bytes::from_static(b"h\xC3")).unwrap();
+        assert_eq!(&*out1, "h");
+        let out2 = decoder.feed(Bytes::from_static(b"\xA9llo")).unwrap();
+        assert_eq!(&*out2, "\u{e9}llo");
+    }
+
+    #[test]
+    fn decoder_reassembles_four_byte_char_split_across_feeds() {
+        // U+1F600 is 0xF0 0x9F 0x98 0x80; split after the 2nd byte.
+        let mut decoder = Utf8Decoder::new();
+        let out1 = decoder.feed(Bytes::from_static(b"\xF0\x9F")).unwrap();
+        assert!(out1.is_empty());
+        let out2 = decoder.feed(Bytes::from_static(b"\x98\x80!")).unwrap();
+        assert_eq!(&*out2, "\u{1F600}!");
+    }
+
+    #[test]
+    fn decoder_lossy_substitutes_mid_stream_invalid_bytes() {
+        let mut decoder = Utf8Decoder::lossy();
+        let out = decoder.feed(Bytes::from_static(b"a\xFFb")).unwrap();
+        assert_eq!(&*out, "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn decoder_strict_errors_on_invalid_bytes() {
+        let mut decoder = Utf8Decoder::new();
+        let err = decoder.feed(Bytes::from_static(b"a\xFFb")).unwrap_err();
+        assert_eq!(err.error_len(), 1);
+        assert_eq!(
+            err.into_extracted().map(|c| c.to_string()),
+            Some("a".to_string())
+        );
+    }
+
+    #[test]
+    fn finish_strict_errors_on_dangling_incomplete_sequence() {
+        let mut decoder = Utf8Decoder::new();
+        decoder.feed(Bytes::from_static(b"a\xC3")).unwrap();
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(err.error_len(), 1);
+    }
+
+    #[test]
+    fn finish_lossy_substitutes_dangling_incomplete_sequence() {
+        let mut decoder = Utf8Decoder::lossy();
+        decoder.feed(Bytes::from_static(b"a\xC3")).unwrap();
+        let out = decoder.finish().unwrap();
+        assert_eq!(&*out, "\u{FFFD}");
+    }
+
+    #[test]
+    fn finish_with_no_pending_bytes_is_empty() {
+        let mut decoder = Utf8Decoder::new();
+        decoder.feed(Bytes::from_static(b"abc")).unwrap();
+        let out = decoder.finish().unwrap();
+        assert!(out.is_empty());
+    }
+}